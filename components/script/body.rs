@@ -12,6 +12,7 @@ use crate::dom::blob::{Blob, BlobImpl};
 use crate::dom::formdata::FormData;
 use crate::dom::globalscope::GlobalScope;
 use crate::dom::promise::Promise;
+use crate::dom::readablestream::ReadableStream;
 use js::jsapi::Heap;
 use js::jsapi::JSContext;
 use js::jsapi::JSObject;
@@ -53,7 +54,9 @@ pub fn consume_body<T: BodyOperations + DomObject>(object: &T, body_type: BodyTy
     let promise = Promise::new(&object.global());
 
     // Step 1
-    if object.get_body_used() || object.is_locked() {
+    let stream = object.body();
+    if object.get_body_used() || object.is_locked() || stream.is_disturbed() || stream.is_locked()
+    {
         promise.reject_error(Error::Type(
             "The response's stream is disturbed or locked".to_string(),
         ));
@@ -62,8 +65,9 @@ pub fn consume_body<T: BodyOperations + DomObject>(object: &T, body_type: BodyTy
 
     object.set_body_promise(&promise, body_type);
 
-    // Steps 2-4
-    // TODO: Body does not yet have a stream.
+    // Steps 2-4: acquire a reader, which locks and disturbs the stream for
+    // the remainder of this read.
+    stream.lock();
 
     consume_body_with_promise(object, body_type, &promise);
 
@@ -179,9 +183,6 @@ fn run_form_data_algorithm(
         .parse()
         .map_err(|_| Error::Type("Inappropriate MIME-type for Body".to_string()))?;
 
-    // TODO
-    // ... Parser for Mime(TopLevel::Multipart, SubLevel::FormData, _)
-    // ... is not fully determined yet.
     if mime.type_() == mime::APPLICATION && mime.subtype() == mime::WWW_FORM_URLENCODED {
         let entries = form_urlencoded::parse(&bytes);
         let formdata = FormData::new(None, root);
@@ -191,9 +192,177 @@ fn run_form_data_algorithm(
         return Ok(FetchedData::FormData(formdata));
     }
 
+    if mime.type_() == mime::MULTIPART && mime.subtype() == mime::FORM_DATA {
+        let boundary = mime
+            .get_param(mime::BOUNDARY)
+            .ok_or_else(|| Error::Type("Missing multipart boundary".to_string()))?;
+        return run_multipart_form_data_algorithm(root, &bytes, boundary.as_str());
+    }
+
     Err(Error::Type("Inappropriate MIME-type for Body".to_string()))
 }
 
+// https://fetch.spec.whatwg.org/#concept-body-package-data
+// multipart/form-data branch, per
+// https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#multipart-form-data
+fn run_multipart_form_data_algorithm(
+    root: &GlobalScope,
+    bytes: &[u8],
+    boundary: &str,
+) -> Fallible<FetchedData> {
+    let formdata = FormData::new(None, root);
+    let delimiter = format!("--{}", boundary).into_bytes();
+
+    let parts = split_multipart_parts(bytes, &delimiter).ok_or_else(|| {
+        Error::Type("Truncated multipart body: missing closing boundary".to_string())
+    })?;
+
+    for part in parts {
+        let (header_bytes, body) = split_part_header(part)
+            .ok_or_else(|| Error::Type("Malformed multipart part".to_string()))?;
+
+        let mut content_disposition = None;
+        let mut content_type = None;
+        for line in String::from_utf8_lossy(header_bytes).split('\n') {
+            let line = line.trim_end_matches('\r');
+            if let Some(value) = strip_ci_prefix(line, "content-disposition:") {
+                content_disposition = Some(value.trim().to_string());
+            } else if let Some(value) = strip_ci_prefix(line, "content-type:") {
+                content_type = Some(value.trim().to_string());
+            }
+        }
+
+        let disposition = content_disposition.ok_or_else(|| {
+            Error::Type("Missing Content-Disposition in multipart part".to_string())
+        })?;
+        let name = disposition_param(&disposition, "name")
+            .ok_or_else(|| Error::Type("Missing name in multipart part".to_string()))?;
+
+        if let Some(filename) = disposition_param(&disposition, "filename") {
+            let mime_string = content_type.unwrap_or_else(|| "text/plain".to_string());
+            let blob = Blob::new(root, BlobImpl::new_from_bytes(body.to_vec()), mime_string);
+            formdata.Append_(USVString(name), &blob, Some(USVString(filename)));
+        } else {
+            formdata.Append(
+                USVString(name),
+                USVString(String::from_utf8_lossy(body).into_owned()),
+            );
+        }
+    }
+
+    Ok(FetchedData::FormData(formdata))
+}
+
+/// Split a multipart body on its boundary delimiter, returning each part's
+/// raw bytes (headers and body, not yet split).
+///
+/// Per RFC 2046, a delimiter is `CRLF "--" boundary`, so a match is only
+/// honored at the very start of the body or right after a line break;
+/// otherwise a part's own content (e.g. a binary file upload) that happens
+/// to contain the literal boundary bytes would be mis-split mid-part.
+///
+/// Returns `None` for a non-empty body that never reaches the closing
+/// `--boundary--` terminator (a truncated or otherwise malformed upload);
+/// an empty body is not an error and yields `Some(vec![])`.
+fn split_multipart_parts<'a>(bytes: &'a [u8], delimiter: &[u8]) -> Option<Vec<&'a [u8]>> {
+    if bytes.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let mut parts = Vec::new();
+    let mut rest = match find_delimiter(bytes, delimiter) {
+        Some(idx) => &bytes[idx + delimiter.len()..],
+        None => return None,
+    };
+
+    loop {
+        let rest_trimmed = trim_leading_crlf(rest);
+        if rest_trimmed.starts_with(b"--") {
+            return Some(parts);
+        }
+        match find_delimiter(rest_trimmed, delimiter) {
+            Some(idx) => {
+                parts.push(trim_trailing_crlf(&rest_trimmed[..idx]));
+                rest = &rest_trimmed[idx + delimiter.len()..];
+            },
+            None => return None,
+        }
+    }
+}
+
+/// Find the next occurrence of `delimiter` that is anchored at the start of
+/// `haystack` or immediately preceded by a line break, ignoring any
+/// occurrence embedded in the middle of a line.
+fn find_delimiter(haystack: &[u8], delimiter: &[u8]) -> Option<usize> {
+    let mut search_from = 0;
+    while let Some(rel_idx) = find_subslice(&haystack[search_from..], delimiter) {
+        let idx = search_from + rel_idx;
+        if idx == 0 || haystack[idx - 1] == b'\n' {
+            return Some(idx);
+        }
+        search_from = idx + 1;
+    }
+    None
+}
+
+/// Split a part into its CRLF-separated header block and body, at the
+/// first blank line.
+fn split_part_header(part: &[u8]) -> Option<(&[u8], &[u8])> {
+    let part = trim_leading_crlf(part);
+    if let Some(idx) = find_subslice(part, b"\r\n\r\n") {
+        Some((&part[..idx], &part[idx + 4..]))
+    } else {
+        find_subslice(part, b"\n\n").map(|idx| (&part[..idx], &part[idx + 2..]))
+    }
+}
+
+fn disposition_param(disposition: &str, param: &str) -> Option<String> {
+    for piece in disposition.split(';').skip(1) {
+        let piece = piece.trim();
+        let eq_idx = piece.find('=')?;
+        let (key, value) = piece.split_at(eq_idx);
+        if key.trim().eq_ignore_ascii_case(param) {
+            return Some(value[1..].trim().trim_matches('"').to_string());
+        }
+    }
+    None
+}
+
+fn strip_ci_prefix<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    if s.len() >= prefix.len() && s[..prefix.len()].eq_ignore_ascii_case(prefix) {
+        Some(&s[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() || haystack.len() < needle.len() {
+        return None;
+    }
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn trim_leading_crlf(bytes: &[u8]) -> &[u8] {
+    if bytes.starts_with(b"\r\n") {
+        &bytes[2..]
+    } else if bytes.starts_with(b"\n") {
+        &bytes[1..]
+    } else {
+        bytes
+    }
+}
+
+fn trim_trailing_crlf(bytes: &[u8]) -> &[u8] {
+    if bytes.ends_with(b"\r\n") {
+        &bytes[..bytes.len() - 2]
+    } else if bytes.ends_with(b"\n") {
+        &bytes[..bytes.len() - 1]
+    } else {
+        bytes
+    }
+}
+
 #[allow(unsafe_code)]
 unsafe fn run_array_buffer_data_algorithm(
     cx: *mut JSContext,
@@ -209,12 +378,91 @@ unsafe fn run_array_buffer_data_algorithm(
     Ok(FetchedData::ArrayBuffer(rooted_heap))
 }
 
+#[cfg(test)]
+mod tests {
+    use super::{disposition_param, split_multipart_parts, split_part_header, strip_ci_prefix};
+
+    #[test]
+    fn split_multipart_parts_handles_crlf() {
+        let body = b"--boundary\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\n1\r\n--boundary\r\nContent-Disposition: form-data; name=\"b\"\r\n\r\n2\r\n--boundary--\r\n";
+        let parts = split_multipart_parts(body, b"--boundary").unwrap();
+        assert_eq!(parts.len(), 2);
+        let (headers, value) = split_part_header(parts[0]).unwrap();
+        assert!(String::from_utf8_lossy(headers).contains("name=\"a\""));
+        assert_eq!(value, b"1");
+    }
+
+    #[test]
+    fn split_multipart_parts_handles_bare_lf() {
+        let body = b"--boundary\nContent-Disposition: form-data; name=\"a\"\n\n1\n--boundary--\n";
+        let parts = split_multipart_parts(body, b"--boundary").unwrap();
+        assert_eq!(parts.len(), 1);
+        let (_, value) = split_part_header(parts[0]).unwrap();
+        assert_eq!(value, b"1");
+    }
+
+    #[test]
+    fn split_multipart_parts_empty_body_yields_no_parts() {
+        assert_eq!(split_multipart_parts(b"", b"--boundary"), Some(Vec::new()));
+    }
+
+    #[test]
+    fn split_multipart_parts_ignores_boundary_bytes_inside_part_content() {
+        // The file's own bytes contain the literal delimiter but with no
+        // preceding line break, so it must not be treated as a real
+        // boundary and split the upload in two.
+        let body = b"--boundary\r\nContent-Disposition: form-data; name=\"f\"; filename=\"x\"\r\n\r\nabc--boundarydef\r\n--boundary--\r\n";
+        let parts = split_multipart_parts(body, b"--boundary").unwrap();
+        assert_eq!(parts.len(), 1);
+        let (_, value) = split_part_header(parts[0]).unwrap();
+        assert_eq!(value, b"abc--boundarydef");
+    }
+
+    #[test]
+    fn split_multipart_parts_missing_terminator_is_an_error() {
+        // Truncated upload: no closing `--boundary--`.
+        let body = b"--boundary\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\n1\r\n";
+        assert_eq!(split_multipart_parts(body, b"--boundary"), None);
+    }
+
+    #[test]
+    fn disposition_param_extracts_name_and_filename() {
+        let disposition = "form-data; name=\"field\"; filename=\"report.txt\"";
+        assert_eq!(
+            disposition_param(disposition, "name"),
+            Some("field".to_string())
+        );
+        assert_eq!(
+            disposition_param(disposition, "filename"),
+            Some("report.txt".to_string())
+        );
+        assert_eq!(disposition_param(disposition, "missing"), None);
+    }
+
+    #[test]
+    fn strip_ci_prefix_is_case_insensitive() {
+        assert_eq!(
+            strip_ci_prefix("Content-Type: text/plain", "content-type:"),
+            Some(" text/plain")
+        );
+        assert_eq!(strip_ci_prefix("X-Other: 1", "content-type:"), None);
+    }
+}
+
 pub trait BodyOperations {
     fn get_body_used(&self) -> bool;
     fn set_body_promise(&self, p: &Rc<Promise>, body_type: BodyType);
-    /// Returns `Some(_)` if the body is complete, `None` if there is more to
-    /// come.
-    fn take_body(&self) -> Option<Vec<u8>>;
+    /// The `ReadableStream` backing this body. Implementors are expected to
+    /// feed it chunks as they arrive from the network thread, rather than
+    /// buffering ahead of time; no type in this crate implements this trait
+    /// yet, so that feeding is not wired up anywhere.
+    fn body(&self) -> DomRoot<ReadableStream>;
     fn is_locked(&self) -> bool;
     fn get_mime_type(&self) -> Ref<Vec<u8>>;
+
+    /// Returns `Some(_)` once the stream has been fully received and
+    /// closed, `None` while more chunks are still expected.
+    fn take_body(&self) -> Option<Vec<u8>> {
+        self.body().take_bytes_if_closed()
+    }
 }
@@ -11,27 +11,34 @@ use crate::dom::bindings::root::{Dom, DomRoot, MutNullableDom};
 use crate::dom::bindings::str::DOMString;
 use crate::dom::cssrulelist::{CSSRuleList, RulesSource};
 use crate::dom::element::Element;
+use crate::dom::promise::Promise;
 use crate::dom::stylesheet::StyleSheet;
 use crate::dom::window::Window;
 use dom_struct::dom_struct;
 use servo_arc::Arc;
 use std::cell::Cell;
+use std::rc::Rc;
 use style::shared_lock::SharedRwLock;
-use style::stylesheets::Stylesheet as StyleStyleSheet;
+use style::stylesheets::{Origin, Stylesheet as StyleStyleSheet};
 
 #[dom_struct]
 pub struct CSSStyleSheet {
     stylesheet: StyleSheet,
-    owner: Dom<Element>,
+    /// `None` for a sheet created via `new CSSStyleSheet()`; per spec such a
+    /// sheet has no owner node.
+    owner: Option<Dom<Element>>,
     rulelist: MutNullableDom<CSSRuleList>,
     #[ignore_malloc_size_of = "Arc"]
     style_stylesheet: Arc<StyleStyleSheet>,
     origin_clean: Cell<bool>,
+    /// Whether this sheet was created via the constructable-stylesheet API,
+    /// as opposed to being parsed from a `<style>`/`<link>` element.
+    constructed: bool,
 }
 
 impl CSSStyleSheet {
     fn new_inherited(
-        owner: &Element,
+        owner: Option<&Element>,
         type_: DOMString,
         href: Option<DOMString>,
         title: Option<DOMString>,
@@ -39,17 +46,18 @@ impl CSSStyleSheet {
     ) -> CSSStyleSheet {
         CSSStyleSheet {
             stylesheet: StyleSheet::new_inherited(type_, href, title),
-            owner: Dom::from_ref(owner),
+            owner: owner.map(Dom::from_ref),
             rulelist: MutNullableDom::new(None),
             style_stylesheet: stylesheet,
             origin_clean: Cell::new(true),
+            constructed: owner.is_none(),
         }
     }
 
     #[allow(unrooted_must_root)]
     pub fn new(
         window: &Window,
-        owner: &Element,
+        owner: Option<&Element>,
         type_: DOMString,
         href: Option<DOMString>,
         title: Option<DOMString>,
@@ -64,6 +72,30 @@ impl CSSStyleSheet {
         )
     }
 
+    // https://drafts.csswg.org/cssom-1/#dom-cssstylesheet-cssstylesheet
+    #[allow(non_snake_case)]
+    pub fn Constructor(window: &Window) -> Fallible<DomRoot<CSSStyleSheet>> {
+        let shared_lock = window.Document().style_shared_lock().clone();
+        let empty = StyleStyleSheet::from_str(
+            "",
+            window.get_url().into(),
+            Origin::Author,
+            shared_lock,
+            None,
+            window.css_error_reporter(),
+            window.Document().quirks_mode(),
+            0,
+        );
+        Ok(CSSStyleSheet::new(
+            window,
+            None,
+            DOMString::from("text/css"),
+            None,
+            None,
+            Arc::new(empty),
+        ))
+    }
+
     fn rulelist(&self) -> DomRoot<CSSRuleList> {
         self.rulelist.or_init(|| {
             let rules = self.style_stylesheet.contents.rules.clone();
@@ -95,6 +127,58 @@ impl CSSStyleSheet {
     pub fn set_origin_clean(&self, origin_clean: bool) {
         self.origin_clean.set(origin_clean);
     }
+
+    pub fn is_constructed(&self) -> bool {
+        self.constructed
+    }
+
+    /// The `adoptedStyleSheets` setter algorithm only accepts constructed
+    /// sheets; per
+    /// https://drafts.csswg.org/cssom-1/#dom-documentorshadowroot-adoptedstylesheets
+    /// setting the list to one that isn't throws `NotAllowedError`.
+    ///
+    /// `Document`/`ShadowRoot` do not yet store an `adopted_style_sheets`
+    /// list or call this check from a setter, so a constructed sheet still
+    /// cannot affect the cascade end-to-end; that storage and cascade
+    /// wiring lives in `document.rs`/`shadowroot.rs`, which are not part of
+    /// this change.
+    pub fn check_adoptable(&self) -> ErrorResult {
+        if self.constructed {
+            Ok(())
+        } else {
+            Err(Error::NotAllowed)
+        }
+    }
+
+    // https://drafts.csswg.org/cssom-1/#dom-cssstylesheet-replacesync
+    fn replace_sync(&self, text: DOMString) -> ErrorResult {
+        if !self.constructed {
+            return Err(Error::NotAllowed);
+        }
+
+        let text = strip_import_rules(&text);
+        let window = self.global().as_window();
+        let new_contents = StyleStyleSheet::from_str(
+            &text,
+            window.get_url().into(),
+            Origin::Author,
+            self.shared_lock().clone(),
+            None,
+            window.css_error_reporter(),
+            window.Document().quirks_mode(),
+            0,
+        );
+
+        {
+            let mut guard = self.shared_lock().write();
+            *self.style_stylesheet.contents.rules.write_with(&mut guard) =
+                new_contents.contents.rules.read_with(&guard).clone();
+        }
+
+        self.rulelist.set(None);
+        window.Document().invalidate_stylesheets();
+        Ok(())
+    }
 }
 
 impl CSSStyleSheetMethods for CSSStyleSheet {
@@ -122,4 +206,36 @@ impl CSSStyleSheetMethods for CSSStyleSheet {
         }
         self.rulelist().remove_rule(index)
     }
+
+    // https://drafts.csswg.org/cssom-1/#dom-cssstylesheet-replace
+    fn Replace(&self, text: DOMString) -> Rc<Promise> {
+        let promise = Promise::new(&self.global());
+        match self.replace_sync(text) {
+            Ok(()) => promise.resolve_native(&DomRoot::from_ref(self)),
+            Err(error) => promise.reject_error(error),
+        }
+        promise
+    }
+
+    // https://drafts.csswg.org/cssom-1/#dom-cssstylesheet-replacesync
+    fn ReplaceSync(&self, text: DOMString) -> ErrorResult {
+        self.replace_sync(text)
+    }
+}
+
+/// Strip `@import` rules from stylesheet text before parsing, since
+/// constructed stylesheets do not load imports, per
+/// https://drafts.csswg.org/cssom-1/#dom-cssstylesheet-replace
+///
+/// Works line by line: every line whose first non-blank text is `@import`
+/// is dropped whole, including any other rule on that line.
+fn strip_import_rules(text: &str) -> String {
+    text.lines()
+        .filter(|line| {
+            let line = line.trim_start();
+            let prefix_len = "@import".len();
+            !(line.len() >= prefix_len && line[..prefix_len].eq_ignore_ascii_case("@import"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
@@ -0,0 +1,147 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use crate::dom::bindings::codegen::Bindings::ReadableStreamBinding;
+use crate::dom::bindings::codegen::Bindings::ReadableStreamBinding::ReadableStreamMethods;
+use crate::dom::bindings::reflector::{reflect_dom_object, Reflector};
+use crate::dom::bindings::root::DomRoot;
+use crate::dom::globalscope::GlobalScope;
+use dom_struct::dom_struct;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// The queue backing one or more `ReadableStream`s. Tee'd streams share a
+/// `Rc` of this so that chunks/close arriving after the tee still reach
+/// every branch, instead of only the branch that was live at tee time.
+#[derive(JSTraceable, MallocSizeOf)]
+struct ReadableStreamQueue {
+    /// Bytes received so far. Readers copy from this rather than draining
+    /// it, so it keeps every byte pushed before the close.
+    chunks: RefCell<Vec<u8>>,
+    closed: Cell<bool>,
+}
+
+impl ReadableStreamQueue {
+    fn new() -> Rc<ReadableStreamQueue> {
+        Rc::new(ReadableStreamQueue {
+            chunks: RefCell::new(Vec::new()),
+            closed: Cell::new(false),
+        })
+    }
+}
+
+/// A minimal implementation of <https://streams.spec.whatwg.org/#rs>, meant
+/// to back Fetch request/response bodies.
+///
+/// This type and the `body()`/`take_body()` contract on `BodyOperations`
+/// (see `body.rs`) are the primitive only: nothing in this crate yet
+/// implements `BodyOperations` or calls `push_chunk`/`close`/`tee`. Wiring
+/// a concrete `Request`/`Response` to feed this from the network thread,
+/// and `Response::clone()` to call `tee()`, lives in `dom/request.rs`,
+/// `dom/response.rs`, and the network-thread body-reading code, none of
+/// which are part of this change — they need to land alongside this file
+/// before streaming bodies are actually usable from script.
+///
+/// Bytes are pushed in as they are received from the network thread and
+/// buffered until the stream is closed; there is no support yet for
+/// backpressure, a BYOB reader, or a custom underlying source.
+#[dom_struct]
+pub struct ReadableStream {
+    reflector_: Reflector,
+    #[ignore_malloc_size_of = "Rc"]
+    queue: Rc<ReadableStreamQueue>,
+    disturbed: Cell<bool>,
+    locked: Cell<bool>,
+}
+
+impl ReadableStream {
+    fn new_inherited(queue: Rc<ReadableStreamQueue>) -> ReadableStream {
+        ReadableStream {
+            reflector_: Reflector::new(),
+            queue,
+            disturbed: Cell::new(false),
+            locked: Cell::new(false),
+        }
+    }
+
+    pub fn new(global: &GlobalScope) -> DomRoot<ReadableStream> {
+        reflect_dom_object(
+            Box::new(ReadableStream::new_inherited(ReadableStreamQueue::new())),
+            global,
+            ReadableStreamBinding::Wrap,
+        )
+    }
+
+    fn new_with_queue(
+        global: &GlobalScope,
+        queue: Rc<ReadableStreamQueue>,
+    ) -> DomRoot<ReadableStream> {
+        reflect_dom_object(
+            Box::new(ReadableStream::new_inherited(queue)),
+            global,
+            ReadableStreamBinding::Wrap,
+        )
+    }
+
+    /// Feed a chunk of bytes received from the network thread into the
+    /// stream's internal queue. A no-op once the stream is closed: chunk
+    /// delivery and close-signaling are both driven by network timing, and
+    /// a chunk arriving just after close is not a programming error.
+    pub fn push_chunk(&self, chunk: &[u8]) {
+        if self.queue.closed.get() {
+            return;
+        }
+        self.queue.chunks.borrow_mut().extend_from_slice(chunk);
+    }
+
+    /// Mark the stream as closed; no further chunks will arrive.
+    pub fn close(&self) {
+        self.queue.closed.set(true);
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.queue.closed.get()
+    }
+
+    pub fn is_disturbed(&self) -> bool {
+        self.disturbed.get()
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked.get()
+    }
+
+    /// Acquire a reader on this stream, per the "disturbed or locked" checks
+    /// in <https://fetch.spec.whatwg.org/#concept-body-consume-body>.
+    pub fn lock(&self) {
+        self.disturbed.set(true);
+        self.locked.set(true);
+    }
+
+    /// Returns `Some(_)` once the stream has been closed and there is
+    /// nothing left to arrive, `None` while more chunks are still expected.
+    ///
+    /// The queue is cloned rather than drained, since it may still be
+    /// shared with a tee'd stream that has yet to be read.
+    pub fn take_bytes_if_closed(&self) -> Option<Vec<u8>> {
+        if !self.queue.closed.get() {
+            return None;
+        }
+        Some(self.queue.chunks.borrow().clone())
+    }
+
+    /// Create a second stream that shares this stream's queue, so that
+    /// chunks pushed (or the close signal) after the tee still reach both
+    /// branches, per <https://streams.spec.whatwg.org/#readablestream-tee>.
+    pub fn tee(&self, global: &GlobalScope) -> DomRoot<ReadableStream> {
+        ReadableStream::new_with_queue(global, Rc::clone(&self.queue))
+    }
+}
+
+impl ReadableStreamMethods for ReadableStream {
+    // https://streams.spec.whatwg.org/#rs-locked
+    fn Locked(&self) -> bool {
+        self.locked.get()
+    }
+}
@@ -14,15 +14,17 @@ extern crate malloc_size_of_derive;
 extern crate serde;
 
 pub mod origin;
+pub mod scope;
 
 pub use crate::origin::{ImmutableOrigin, MutableOrigin, OpaqueOrigin};
+pub use crate::scope::UrlScope;
 
 use std::fmt;
 use std::net::IpAddr;
 use std::ops::{Index, Range, RangeFrom, RangeFull, RangeTo};
 use std::path::Path;
 use std::sync::Arc;
-use url::{Position, Url};
+use url::{form_urlencoded, Position, Url};
 
 pub use url::Host;
 
@@ -159,6 +161,80 @@ impl ServoUrl {
     pub fn from_file_path<P: AsRef<Path>>(path: P) -> Result<Self, ()> {
         Ok(Self::from_url(Url::from_file_path(path)?))
     }
+
+    /// The query string's key/value pairs, in order, with repeated keys
+    /// preserved as separate entries.
+    pub fn query_pairs(&self) -> Vec<(String, String)> {
+        self.0
+            .query_pairs()
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect()
+    }
+
+    /// The value of the first query pair matching `key`, if any.
+    pub fn get_query(&self, key: &str) -> Option<String> {
+        self.query_pairs()
+            .into_iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Set the value of the first query pair matching `key`, appending a
+    /// new pair if none exists, and removing any other pairs with the same
+    /// key, per the `URLSearchParams.set()` semantics this mirrors.
+    pub fn set_query_pair(&mut self, key: &str, value: &str) {
+        let mut replaced = false;
+        let mut pairs: Vec<(String, String)> = Vec::new();
+        for (k, v) in self.query_pairs() {
+            if k != key {
+                pairs.push((k, v));
+            } else if !replaced {
+                pairs.push((k, value.to_owned()));
+                replaced = true;
+            }
+        }
+        if !replaced {
+            pairs.push((key.to_owned(), value.to_owned()));
+        }
+        self.write_query_pairs(&pairs);
+    }
+
+    /// Append a new query pair, keeping any existing pairs with the same
+    /// key.
+    pub fn append_query_pair(&mut self, key: &str, value: &str) {
+        let mut pairs = self.query_pairs();
+        pairs.push((key.to_owned(), value.to_owned()));
+        self.write_query_pairs(&pairs);
+    }
+
+    /// Remove every query pair matching `key`.
+    pub fn remove_query(&mut self, key: &str) {
+        let pairs: Vec<_> = self
+            .query_pairs()
+            .into_iter()
+            .filter(|(k, _)| k != key)
+            .collect();
+        self.write_query_pairs(&pairs);
+    }
+
+    /// Stably sort the query pairs by key.
+    pub fn sort_query(&mut self) {
+        let mut pairs = self.query_pairs();
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+        self.write_query_pairs(&pairs);
+    }
+
+    fn write_query_pairs(&mut self, pairs: &[(String, String)]) {
+        if pairs.is_empty() {
+            self.as_mut_url().set_query(None);
+            return;
+        }
+        let mut serializer = form_urlencoded::Serializer::new(String::new());
+        for (key, value) in pairs {
+            serializer.append_pair(key, value);
+        }
+        self.as_mut_url().set_query(Some(&serializer.finish()));
+    }
 }
 
 impl fmt::Display for ServoUrl {
@@ -224,3 +300,98 @@ impl<'de> serde::Deserialize<'de> for ServoUrl {
         url_serde::deserialize(deserializer).map(Self::from_url)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ServoUrl;
+
+    #[test]
+    fn query_pairs_preserves_order_and_repeated_keys() {
+        let url = ServoUrl::parse("https://example.com/?a=1&b=2&a=3").unwrap();
+        assert_eq!(
+            url.query_pairs(),
+            vec![
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "2".to_string()),
+                ("a".to_string(), "3".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn query_pairs_round_trips_empty_values() {
+        let url = ServoUrl::parse("https://example.com/?a=&b=2").unwrap();
+        assert_eq!(
+            url.query_pairs(),
+            vec![
+                ("a".to_string(), "".to_string()),
+                ("b".to_string(), "2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn get_query_returns_first_match() {
+        let url = ServoUrl::parse("https://example.com/?a=1&a=2").unwrap();
+        assert_eq!(url.get_query("a"), Some("1".to_string()));
+        assert_eq!(url.get_query("missing"), None);
+    }
+
+    #[test]
+    fn set_query_pair_replaces_first_and_drops_duplicates() {
+        let mut url = ServoUrl::parse("https://example.com/?a=1&a=2&b=3").unwrap();
+        url.set_query_pair("a", "x");
+        assert_eq!(url.query(), Some("a=x&b=3"));
+    }
+
+    #[test]
+    fn set_query_pair_appends_when_key_is_absent() {
+        let mut url = ServoUrl::parse("https://example.com/?a=1").unwrap();
+        url.set_query_pair("b", "2");
+        assert_eq!(url.query(), Some("a=1&b=2"));
+    }
+
+    #[test]
+    fn append_query_pair_keeps_existing_same_key_pairs() {
+        let mut url = ServoUrl::parse("https://example.com/?a=1").unwrap();
+        url.append_query_pair("a", "2");
+        assert_eq!(url.query(), Some("a=1&a=2"));
+    }
+
+    #[test]
+    fn remove_query_drops_every_matching_pair() {
+        let mut url = ServoUrl::parse("https://example.com/?a=1&b=2&a=3").unwrap();
+        url.remove_query("a");
+        assert_eq!(url.query(), Some("b=2"));
+    }
+
+    #[test]
+    fn remove_query_clears_query_string_when_empty() {
+        let mut url = ServoUrl::parse("https://example.com/?a=1").unwrap();
+        url.remove_query("a");
+        assert_eq!(url.query(), None);
+    }
+
+    #[test]
+    fn sort_query_is_stable_by_key() {
+        let mut url = ServoUrl::parse("https://example.com/?b=1&a=2&b=3&a=4").unwrap();
+        url.sort_query();
+        assert_eq!(
+            url.query_pairs(),
+            vec![
+                ("a".to_string(), "2".to_string()),
+                ("a".to_string(), "4".to_string()),
+                ("b".to_string(), "1".to_string()),
+                ("b".to_string(), "3".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn write_query_pairs_percent_encodes_on_write() {
+        let mut url = ServoUrl::parse("https://example.com/").unwrap();
+        url.set_query_pair("q", "a b&c");
+        assert_eq!(url.query(), Some("q=a+b%26c"));
+        assert_eq!(url.get_query("q"), Some("a b&c".to_string()));
+    }
+}
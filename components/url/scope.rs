@@ -0,0 +1,221 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use crate::ServoUrl;
+use std::path::{Component, Path, PathBuf};
+
+/// A policy deciding which `file://` paths and custom-scheme URLs a page may
+/// load, for embedders running untrusted content.
+///
+/// A URL is in scope when at least one allow pattern matches it and no deny
+/// pattern does; deny always wins over allow, matching Tauri's `FsScope`
+/// precedence rules.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct UrlScope {
+    allow: Vec<String>,
+    deny: Vec<String>,
+}
+
+impl UrlScope {
+    pub fn new() -> Self {
+        UrlScope::default()
+    }
+
+    /// Allow a single file.
+    pub fn allow_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        if let Some(pattern) = normalize_pattern(path.as_ref()) {
+            self.allow.push(pattern);
+        }
+        self
+    }
+
+    /// Allow a directory; when `recursive` is true, also allow everything
+    /// below it.
+    pub fn allow_directory<P: AsRef<Path>>(mut self, path: P, recursive: bool) -> Self {
+        if let Some(pattern) = directory_pattern(path.as_ref(), recursive) {
+            self.allow.push(pattern);
+        }
+        self
+    }
+
+    /// Deny a single file, overriding any allow pattern that would
+    /// otherwise match it.
+    pub fn forbid_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        if let Some(pattern) = normalize_pattern(path.as_ref()) {
+            self.deny.push(pattern);
+        }
+        self
+    }
+
+    /// Deny a directory; when `recursive` is true, also deny everything
+    /// below it.
+    pub fn forbid_directory<P: AsRef<Path>>(mut self, path: P, recursive: bool) -> Self {
+        if let Some(pattern) = directory_pattern(path.as_ref(), recursive) {
+            self.deny.push(pattern);
+        }
+        self
+    }
+
+    /// Allow URLs whose string form (`scheme://...`) matches `pattern`.
+    ///
+    /// Unlike `allow_file`/`allow_directory`, this matches against the
+    /// full URL string rather than a filesystem path, since a custom-scheme
+    /// URL has no path to canonicalize; it's how non-`file://` schemes get
+    /// into the allow list at all.
+    pub fn allow_url(mut self, pattern: &str) -> Self {
+        self.allow.push(pattern.to_owned());
+        self
+    }
+
+    /// Deny URLs whose string form matches `pattern`. See `allow_url`.
+    pub fn forbid_url(mut self, pattern: &str) -> Self {
+        self.deny.push(pattern.to_owned());
+        self
+    }
+
+    /// Allow every URL under a given custom scheme, e.g. `allow_scheme("myapp")`
+    /// allows `myapp://...` URLs. Shorthand for `allow_url("{scheme}://**")`.
+    pub fn allow_scheme(self, scheme: &str) -> Self {
+        self.allow_url(&format!("{}://**", scheme))
+    }
+
+    /// Deny every URL under a given custom scheme. See `allow_scheme`.
+    pub fn forbid_scheme(self, scheme: &str) -> Self {
+        self.forbid_url(&format!("{}://**", scheme))
+    }
+
+    /// Returns true only when some allow pattern matches `url` and no deny
+    /// pattern does.
+    pub fn matches(&self, url: &ServoUrl) -> bool {
+        let candidate = url
+            .to_file_path()
+            .ok()
+            .and_then(|path| path.to_str().map(str::to_owned))
+            .unwrap_or_else(|| url.as_str().to_owned());
+
+        if self.deny.iter().any(|pattern| glob_match(pattern, &candidate)) {
+            return false;
+        }
+        self.allow.iter().any(|pattern| glob_match(pattern, &candidate))
+    }
+}
+
+/// Normalize a path to a `..`-free string, rejecting patterns that would
+/// escape their base via a parent-directory component.
+fn normalize_pattern(path: &Path) -> Option<String> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir => return None,
+            Component::CurDir => {},
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    normalized.to_str().map(str::to_owned)
+}
+
+fn directory_pattern(path: &Path, recursive: bool) -> Option<String> {
+    let base = normalize_pattern(path)?;
+    let base = base.trim_end_matches('/');
+    Some(if recursive {
+        format!("{}/**", base)
+    } else {
+        format!("{}/*", base)
+    })
+}
+
+/// A small glob matcher supporting `*` (a run of characters excluding `/`),
+/// `**` (a run of characters including `/`), and `?` (a single character).
+/// A trailing `/**` also matches nothing at all, so `dir/**` matches `dir`.
+fn glob_match(pattern: &str, candidate: &str) -> bool {
+    glob_match_bytes(pattern.as_bytes(), candidate.as_bytes())
+}
+
+fn glob_match_bytes(pattern: &[u8], candidate: &[u8]) -> bool {
+    match pattern.first() {
+        None => candidate.is_empty(),
+        Some(b'*') if pattern.get(1) == Some(&b'*') => {
+            let rest = &pattern[2..];
+            (0..=candidate.len()).any(|i| glob_match_bytes(rest, &candidate[i..]))
+        },
+        Some(b'*') => {
+            let rest = &pattern[1..];
+            let max = candidate
+                .iter()
+                .position(|&byte| byte == b'/')
+                .unwrap_or(candidate.len());
+            (0..=max).any(|i| glob_match_bytes(rest, &candidate[i..]))
+        },
+        Some(b'?') => !candidate.is_empty() && glob_match_bytes(&pattern[1..], &candidate[1..]),
+        Some(b'/') if &pattern[1..] == b"**" && candidate.is_empty() => true,
+        Some(&byte) => {
+            !candidate.is_empty()
+                && candidate[0] == byte
+                && glob_match_bytes(&pattern[1..], &candidate[1..])
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{glob_match, UrlScope};
+    use crate::ServoUrl;
+
+    #[test]
+    fn glob_match_star_does_not_cross_slash() {
+        assert!(glob_match("/tmp/*", "/tmp/foo"));
+        assert!(!glob_match("/tmp/*", "/tmp/foo/bar"));
+    }
+
+    #[test]
+    fn glob_match_double_star_crosses_slash() {
+        assert!(glob_match("/tmp/**", "/tmp/foo/bar/baz"));
+        assert!(glob_match("/tmp/**", "/tmp"));
+    }
+
+    #[test]
+    fn glob_match_question_mark_is_single_char() {
+        assert!(glob_match("/tmp/?.txt", "/tmp/a.txt"));
+        assert!(!glob_match("/tmp/?.txt", "/tmp/ab.txt"));
+        assert!(!glob_match("/tmp/?.txt", "/tmp/.txt"));
+    }
+
+    #[test]
+    fn deny_always_wins_over_allow() {
+        let scope = UrlScope::new()
+            .allow_directory("/tmp", true)
+            .forbid_file("/tmp/secret");
+        assert!(scope.matches(&ServoUrl::parse("file:///tmp/ok").unwrap()));
+        assert!(!scope.matches(&ServoUrl::parse("file:///tmp/secret").unwrap()));
+    }
+
+    #[test]
+    fn non_recursive_directory_does_not_allow_subdirectories() {
+        let scope = UrlScope::new().allow_directory("/tmp", false);
+        assert!(scope.matches(&ServoUrl::parse("file:///tmp/ok").unwrap()));
+        assert!(!scope.matches(&ServoUrl::parse("file:///tmp/nested/ok").unwrap()));
+    }
+
+    #[test]
+    fn parent_dir_escape_is_rejected() {
+        let scope = UrlScope::new().allow_directory("/tmp/../etc", true);
+        assert!(!scope.matches(&ServoUrl::parse("file:///etc/passwd").unwrap()));
+    }
+
+    #[test]
+    fn custom_scheme_can_be_allowed() {
+        let scope = UrlScope::new().allow_scheme("myapp");
+        assert!(scope.matches(&ServoUrl::parse("myapp://settings/page").unwrap()));
+        assert!(!scope.matches(&ServoUrl::parse("otherapp://settings/page").unwrap()));
+    }
+
+    #[test]
+    fn custom_scheme_deny_wins_over_allow() {
+        let scope = UrlScope::new()
+            .allow_scheme("myapp")
+            .forbid_url("myapp://settings/secret");
+        assert!(scope.matches(&ServoUrl::parse("myapp://settings/page").unwrap()));
+        assert!(!scope.matches(&ServoUrl::parse("myapp://settings/secret").unwrap()));
+    }
+}